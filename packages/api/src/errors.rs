@@ -0,0 +1,20 @@
+use pyo3::{create_exception, exceptions::PyRuntimeError, type_object::PyTypeObject, PyErr};
+use std::fmt::Display;
+
+// Raised when a call traps because a metered store ran out of gas
+// points; see `Metering`.
+create_exception!(wasmer, OutOfGas, PyRuntimeError);
+
+/// Turns any displayable error or message into a `PyErr` of the
+/// given Python exception type `E`.
+///
+/// This centralizes the `message.to_string()` dance so call sites
+/// only need to name the exception they want to raise, e.g.
+/// `to_py_err::<PyIndexError, _>("out of bound")`.
+pub fn to_py_err<E, M>(message: M) -> PyErr
+where
+    E: PyTypeObject,
+    M: Display,
+{
+    PyErr::new::<E, _>(message.to_string())
+}
@@ -0,0 +1,99 @@
+use crate::wasmer_inner::wasmer;
+use pyo3::prelude::*;
+
+/// Selects which WebAssembly proposals are enabled when validating,
+/// translating or compiling a module.
+///
+/// Defaults match the proposals Wasmer enables by default
+/// (bulk-memory, reference-types, multi-value); everything else
+/// must be opted into explicitly.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store, Features
+///
+/// store = Store(features=Features(simd=True, threads=True))
+/// ```
+#[pyclass]
+#[derive(Clone, Copy)]
+pub struct Features {
+    pub(crate) threads: bool,
+    pub(crate) simd: bool,
+    pub(crate) reference_types: bool,
+    pub(crate) bulk_memory: bool,
+    pub(crate) multi_value: bool,
+    pub(crate) tail_call: bool,
+}
+
+#[pymethods]
+impl Features {
+    #[new]
+    #[args(
+        threads = "false",
+        simd = "false",
+        reference_types = "true",
+        bulk_memory = "true",
+        multi_value = "true",
+        tail_call = "false"
+    )]
+    fn new(
+        threads: bool,
+        simd: bool,
+        reference_types: bool,
+        bulk_memory: bool,
+        multi_value: bool,
+        tail_call: bool,
+    ) -> Self {
+        Self {
+            threads,
+            simd,
+            reference_types,
+            bulk_memory,
+            multi_value,
+            tail_call,
+        }
+    }
+}
+
+impl Default for Features {
+    fn default() -> Self {
+        Self {
+            threads: false,
+            simd: false,
+            reference_types: true,
+            bulk_memory: true,
+            multi_value: true,
+            tail_call: false,
+        }
+    }
+}
+
+impl From<Features> for wasmer::Features {
+    fn from(value: Features) -> Self {
+        let mut features = wasmer::Features::new();
+        features
+            .threads(value.threads)
+            .reference_types(value.reference_types)
+            .simd(value.simd)
+            .bulk_memory(value.bulk_memory)
+            .multi_value(value.multi_value)
+            .tail_call(value.tail_call);
+
+        features
+    }
+}
+
+impl From<Features> for wasmer::wasmparser::WasmFeatures {
+    fn from(value: Features) -> Self {
+        wasmer::wasmparser::WasmFeatures {
+            threads: value.threads,
+            reference_types: value.reference_types,
+            simd: value.simd,
+            bulk_memory: value.bulk_memory,
+            multi_value: value.multi_value,
+            tail_call: value.tail_call,
+            ..wasmer::wasmparser::WasmFeatures::default()
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    errors::{to_py_err, OutOfGas},
+    module::Module,
+    store::Store,
+    wasmer_inner::wasmer,
+};
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+    types::PyTuple,
+};
+use wasmer_middlewares::metering;
+
+/// Represents an instantiated WebAssembly module: a `Module` paired
+/// with the runtime state (memories, tables, globals, function
+/// bodies) needed to call into it.
+///
+/// Instantiating against a metered `Store` (see `Metering`) gives
+/// this instance its own gas budget, tracked independently of any
+/// other `Instance` created from the same store; see
+/// `Instance.get_remaining_points` and `Instance.set_remaining_points`.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store, Module, Instance
+///
+/// store = Store()
+/// module = Module(store, open('tests/tests.wasm', 'rb').read())
+/// instance = Instance(store, module)
+/// ```
+#[pyclass]
+pub struct Instance {
+    inner: wasmer::Instance,
+    is_metered: bool,
+}
+
+impl Instance {
+    fn metered_instance(&self) -> PyResult<&wasmer::Instance> {
+        if !self.is_metered {
+            return Err(to_py_err::<PyRuntimeError, _>(
+                "This instance's store was not created with a `Metering` configuration",
+            ));
+        }
+
+        Ok(&self.inner)
+    }
+}
+
+#[pymethods]
+impl Instance {
+    #[new]
+    fn new(store: &Store, module: &Module) -> PyResult<Self> {
+        let import_object = wasmer::ImportObject::new();
+        let inner = wasmer::Instance::new(module.inner(), &import_object)
+            .map_err(to_py_err::<PyRuntimeError, _>)?;
+
+        Ok(Self {
+            inner,
+            is_metered: store.is_metered(),
+        })
+    }
+
+    /// Calls the exported function `name`, coercing `arguments`
+    /// according to the function's declared parameter types.
+    ///
+    /// If this instance is metered and the call traps because its
+    /// gas points are exhausted, raises `OutOfGas` instead of a
+    /// generic `RuntimeError`.
+    #[args(arguments = "*")]
+    fn call(&self, name: &str, arguments: &PyTuple) -> PyResult<Vec<PyObject>> {
+        let function = self
+            .inner
+            .exports
+            .get_function(name)
+            .map_err(to_py_err::<PyRuntimeError, _>)?;
+
+        let params = function.ty().params();
+
+        if arguments.len() != params.len() {
+            return Err(to_py_err::<PyValueError, _>(format!(
+                "`{}` expects {} argument(s), got {}",
+                name,
+                params.len(),
+                arguments.len()
+            )));
+        }
+
+        let values = arguments
+            .iter()
+            .zip(params)
+            .map(|(argument, ty)| value_for(ty, argument))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let results = function.call(&values).map_err(|error| {
+            if self.is_metered
+                && matches!(
+                    metering::get_remaining_points(&self.inner),
+                    metering::MeteringPoints::Exhausted
+                )
+            {
+                to_py_err::<OutOfGas, _>("Execution ran out of gas points")
+            } else {
+                to_py_err::<PyRuntimeError, _>(error.to_string())
+            }
+        })?;
+
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+
+        Ok(results
+            .iter()
+            .map(|value| match value {
+                wasmer::Value::I32(value) => value.into_py(py),
+                wasmer::Value::I64(value) => value.into_py(py),
+                wasmer::Value::F32(value) => value.into_py(py),
+                wasmer::Value::F64(value) => value.into_py(py),
+                _ => py.None(),
+            })
+            .collect())
+    }
+
+    /// Returns the number of gas points left before execution traps.
+    ///
+    /// Raises `RuntimeError` if this instance's store wasn't created
+    /// with a `Metering` configuration.
+    fn get_remaining_points(&self) -> PyResult<u64> {
+        let instance = self.metered_instance()?;
+
+        Ok(match metering::get_remaining_points(instance) {
+            metering::MeteringPoints::Remaining(points) => points,
+            metering::MeteringPoints::Exhausted => 0,
+        })
+    }
+
+    /// Resets or tops up the number of gas points available before
+    /// the next trap.
+    fn set_remaining_points(&self, points: u64) -> PyResult<()> {
+        let instance = self.metered_instance()?;
+        metering::set_remaining_points(instance, points);
+
+        Ok(())
+    }
+}
+
+/// Converts a Python argument into the `wasmer::Value` variant
+/// declared by the callee's signature, rather than guessing a variant
+/// from the Python value's own type.
+fn value_for(ty: &wasmer::ValType, argument: &PyAny) -> PyResult<wasmer::Value> {
+    let value = match ty {
+        wasmer::ValType::I32 => argument.extract::<i32>().map(wasmer::Value::I32),
+        wasmer::ValType::I64 => argument.extract::<i64>().map(wasmer::Value::I64),
+        wasmer::ValType::F32 => argument.extract::<f32>().map(wasmer::Value::F32),
+        wasmer::ValType::F64 => argument.extract::<f64>().map(wasmer::Value::F64),
+        _ => {
+            return Err(to_py_err::<PyValueError, _>(format!(
+                "Arguments of type `{:?}` can't be passed from Python yet",
+                ty
+            )))
+        }
+    };
+
+    value.map_err(|_: PyErr| {
+        to_py_err::<PyValueError, _>(format!("Expected an argument convertible to `{:?}`", ty))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metering::Metering;
+    use pyo3::types::PyBytes;
+
+    fn metered_store(limit: u64) -> Store {
+        Store::new(
+            Some(Metering {
+                limit,
+                cost: Python::with_gil(|py| {
+                    py.eval("lambda operator: 1", None, None).unwrap().into()
+                }),
+            }),
+            None,
+        )
+    }
+
+    fn counter_module(store: &Store) -> Module {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (func (export "increment") (param i32) (result i32)
+                    local.get 0
+                    i32.const 1
+                    i32.add))
+            "#,
+        )
+        .unwrap();
+
+        Python::with_gil(|py| Module::new(store, PyBytes::new(py, &wasm)).unwrap())
+    }
+
+    #[test]
+    fn call_coerces_arguments_to_the_declared_parameter_type() {
+        let store = Store::new(None, None);
+        let module = counter_module(&store);
+        let instance = Instance::new(&store, &module).unwrap();
+
+        let result = Python::with_gil(|py| {
+            let arguments = PyTuple::new(py, &[41i32.into_py(py)]);
+            instance.call("increment", arguments).unwrap()
+        });
+
+        Python::with_gil(|py| {
+            assert_eq!(result[0].extract::<i32>(py).unwrap(), 42);
+        });
+    }
+
+    #[test]
+    fn get_remaining_points_requires_a_metering_configuration() {
+        let store = Store::new(None, None);
+        let module = counter_module(&store);
+        let instance = Instance::new(&store, &module).unwrap();
+
+        assert!(instance.get_remaining_points().is_err());
+    }
+
+    #[test]
+    fn a_metered_call_consumes_gas_points() {
+        let store = metered_store(1_000);
+        let module = counter_module(&store);
+        let instance = Instance::new(&store, &module).unwrap();
+
+        let before = instance.get_remaining_points().unwrap();
+
+        Python::with_gil(|py| {
+            let arguments = PyTuple::new(py, &[1i32.into_py(py)]);
+            instance.call("increment", arguments).unwrap();
+        });
+
+        assert!(instance.get_remaining_points().unwrap() < before);
+    }
+
+    #[test]
+    fn exhausting_gas_points_raises_out_of_gas() {
+        let store = metered_store(1_000);
+        let module = counter_module(&store);
+        let instance = Instance::new(&store, &module).unwrap();
+
+        instance.set_remaining_points(0).unwrap();
+
+        let error = Python::with_gil(|py| {
+            let arguments = PyTuple::new(py, &[1i32.into_py(py)]);
+            instance.call("increment", arguments).unwrap_err()
+        });
+
+        Python::with_gil(|py| {
+            assert!(error.is_instance::<OutOfGas>(py));
+        });
+    }
+}
@@ -0,0 +1,108 @@
+use errors::to_py_err;
+use pyo3::{exceptions::PyRuntimeError, prelude::*, types::PyBytes, wrap_pyfunction};
+
+pub(crate) mod wasmer_inner {
+    pub use wasmer;
+}
+
+pub mod errors;
+mod features;
+mod instance;
+mod memory;
+mod metering;
+mod module;
+mod store;
+mod types;
+
+pub use errors::OutOfGas;
+pub use features::Features;
+pub use instance::Instance;
+pub use memory::views::{
+    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, Uint16Array,
+    Uint32Array, Uint64Array, Uint8Array,
+};
+pub use metering::Metering;
+pub use module::Module;
+pub use store::Store;
+pub use types::{ExportType, ImportType};
+
+/// Runs `bytes` through a validating section walk for the given
+/// `features` (or the default feature set if `None`), without
+/// compiling it.
+#[pyfunction]
+#[text_signature = "(bytes, features=None)"]
+#[args(features = "None")]
+fn validate(bytes: &PyBytes, features: Option<Features>) -> PyResult<bool> {
+    validate_bytes(bytes.as_bytes(), features).map(|()| true)
+}
+
+/// Translates WebAssembly text format source to the WebAssembly
+/// binary format, then validates the result against `features`.
+#[pyfunction]
+#[text_signature = "(wat, features=None)"]
+#[args(features = "None")]
+fn wat2wasm<'py>(
+    py: Python<'py>,
+    wat: String,
+    features: Option<Features>,
+) -> PyResult<&'py PyBytes> {
+    let bytes = wat::parse_str(wat).map_err(to_py_err::<PyRuntimeError, _>)?;
+    validate_bytes(&bytes, features)?;
+
+    Ok(PyBytes::new(py, &bytes))
+}
+
+/// Disassembles WebAssembly binary format to WebAssembly text
+/// format.
+#[pyfunction]
+#[text_signature = "(bytes)"]
+fn wasm2wat(bytes: &PyBytes) -> PyResult<String> {
+    wasmprinter::print_bytes(bytes.as_bytes()).map_err(to_py_err::<PyRuntimeError, _>)
+}
+
+fn validate_bytes(bytes: &[u8], features: Option<Features>) -> PyResult<()> {
+    let mut validator = wasmer_inner::wasmer::wasmparser::Validator::new();
+    validator.wasm_features(features.unwrap_or_default().into());
+
+    validator.validate_all(bytes).map(|_| ()).map_err(|error| {
+        to_py_err::<PyRuntimeError, _>(format!(
+            "Invalid WebAssembly module at offset {}: {}",
+            error.offset(),
+            error
+        ))
+    })
+}
+
+/// This extension allows to compile and to execute WebAssembly
+/// programs.
+#[pymodule]
+fn wasmer(py: Python, module: &PyModule) -> PyResult<()> {
+    // Functions.
+    module.add_wrapped(wrap_pyfunction!(validate))?;
+    module.add_wrapped(wrap_pyfunction!(wat2wasm))?;
+    module.add_wrapped(wrap_pyfunction!(wasm2wat))?;
+
+    // Classes.
+    module.add_class::<Module>()?;
+    module.add_class::<Store>()?;
+    module.add_class::<Instance>()?;
+    module.add_class::<Metering>()?;
+    module.add_class::<Features>()?;
+    module.add_class::<ExportType>()?;
+    module.add_class::<ImportType>()?;
+    module.add_class::<Uint8Array>()?;
+    module.add_class::<Int8Array>()?;
+    module.add_class::<Uint16Array>()?;
+    module.add_class::<Int16Array>()?;
+    module.add_class::<Uint32Array>()?;
+    module.add_class::<Int32Array>()?;
+    module.add_class::<Uint64Array>()?;
+    module.add_class::<Int64Array>()?;
+    module.add_class::<Float32Array>()?;
+    module.add_class::<Float64Array>()?;
+
+    // Exceptions.
+    module.add("OutOfGas", py.get_type::<OutOfGas>())?;
+
+    Ok(())
+}
@@ -1,14 +1,135 @@
 use crate::{errors::to_py_err, wasmer_inner::wasmer};
 use pyo3::{
-    class::PyMappingProtocol,
+    class::{PyBufferProtocol, PyMappingProtocol},
     exceptions::{PyIndexError, PyRuntimeError, PyValueError},
+    ffi,
     prelude::*,
-    types::{PyAny, PyInt, PyLong, PySequence, PySlice},
+    types::{PyAny, PyBytes, PyInt, PyLong, PySequence, PySlice},
+    AsPyPointer,
 };
-use std::{cell::Cell, cmp::min, ops::Range};
+use std::{cell::Cell, mem::size_of, ops::Range, os::raw::c_int, ptr};
+
+/// Resolves a possibly-negative Python index against a sequence of
+/// length `len` (as a caller-supplied view would expose it, e.g. via
+/// `__len__`), returning `None` if it falls outside `0..len`.
+fn resolve_index(index: isize, len: usize) -> Option<usize> {
+    let resolved = if index < 0 {
+        index + len as isize
+    } else {
+        index
+    };
+
+    if resolved < 0 || resolved as usize >= len {
+        None
+    } else {
+        Some(resolved as usize)
+    }
+}
+
+/// Expands a `start`/`step`/`slicelength` triple, as already resolved
+/// by `PySlice::indices`, into the concrete sequence of element
+/// indices it selects. Supports positive and negative steps alike.
+fn slice_elements(start: isize, step: isize, slicelength: isize) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(slicelength.max(0) as usize);
+    let mut current = start;
+
+    for _ in 0..slicelength.max(0) {
+        indices.push(current as usize);
+        current += step;
+    }
+
+    indices
+}
+
+/// Why `read`/`write` rejected an `offset..offset + length` range.
+#[derive(Debug, PartialEq, Eq)]
+enum RangeError {
+    /// `offset + length` doesn't fit in a `usize`.
+    Overflow,
+    /// The range fits in a `usize` but runs past the view's end.
+    OutOfBounds,
+}
+
+/// Checks that `offset..offset + length` is a valid range into a view
+/// of `view_len` elements, used by both `read` and `write` to bounds
+/// their access before touching guest memory.
+fn checked_range(offset: usize, length: usize, view_len: usize) -> Result<Range<usize>, RangeError> {
+    let end = offset.checked_add(length).ok_or(RangeError::Overflow)?;
+
+    if end > view_len {
+        Err(RangeError::OutOfBounds)
+    } else {
+        Ok(offset..end)
+    }
+}
+
+/// Whether a buffer of `buffer_len` bytes holds a whole number of
+/// `bytes_per_element`-sized elements, as `write` requires.
+fn is_whole_number_of_elements(buffer_len: usize, bytes_per_element: usize) -> bool {
+    buffer_len % bytes_per_element == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_index_handles_negative_indices() {
+        assert_eq!(resolve_index(0, 10), Some(0));
+        assert_eq!(resolve_index(9, 10), Some(9));
+        assert_eq!(resolve_index(-1, 10), Some(9));
+        assert_eq!(resolve_index(-10, 10), Some(0));
+    }
+
+    #[test]
+    fn resolve_index_rejects_out_of_bound_indices() {
+        assert_eq!(resolve_index(10, 10), None);
+        assert_eq!(resolve_index(-11, 10), None);
+    }
+
+    #[test]
+    fn slice_elements_supports_positive_and_negative_steps() {
+        // `list(range(10))[1:8:2]`
+        assert_eq!(slice_elements(1, 2, 4), vec![1, 3, 5, 7]);
+        // `list(range(10))[8:1:-2]`
+        assert_eq!(slice_elements(8, -2, 4), vec![8, 6, 4, 2]);
+    }
+
+    #[test]
+    fn slice_elements_is_empty_for_a_zero_length_slice() {
+        assert_eq!(slice_elements(3, 1, 0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn checked_range_accepts_a_range_that_fits_the_view() {
+        assert_eq!(checked_range(2, 3, 10), Ok(2..5));
+        assert_eq!(checked_range(0, 10, 10), Ok(0..10));
+    }
+
+    #[test]
+    fn checked_range_rejects_a_range_past_the_end_of_the_view() {
+        assert_eq!(checked_range(8, 3, 10), Err(RangeError::OutOfBounds));
+        assert_eq!(checked_range(10, 1, 10), Err(RangeError::OutOfBounds));
+    }
+
+    #[test]
+    fn checked_range_rejects_an_overflowing_offset_and_length() {
+        assert_eq!(
+            checked_range(usize::MAX, 1, 10),
+            Err(RangeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn is_whole_number_of_elements_checks_the_buffer_divides_evenly() {
+        assert!(is_whole_number_of_elements(8, 4));
+        assert!(is_whole_number_of_elements(0, 4));
+        assert!(!is_whole_number_of_elements(6, 4));
+    }
+}
 
 macro_rules! memory_view {
-    ($class_name:ident over $wasm_type:ty | $bytes_per_element:expr) => {
+    ($class_name:ident over $wasm_type:ty | $bytes_per_element:expr | $format:expr) => {
         /// Represents a read-and-write view over the data of a
         /// memory.
         ///
@@ -20,6 +141,25 @@ macro_rules! memory_view {
         ///
         /// [mapping-protocol]: https://docs.python.org/3/c-api/mapping.html
         ///
+        /// ## Buffer protocol caveat
+        ///
+        /// This view also implements the [buffer
+        /// protocol][buffer-protocol] (`__buffer__`), so it can be
+        /// handed to `numpy.frombuffer`, `bytes()`, `memoryview()`
+        /// and `struct.unpack` without copying: the buffer points
+        /// directly at the guest's linear memory.
+        ///
+        /// Because of that, a buffer obtained this way is only valid
+        /// for as long as the backing `Memory` isn't grown: growing
+        /// linear memory may reallocate it, so any `numpy` array or
+        /// `memoryview` built from this view *must* be dropped
+        /// before the memory grows, and re-created afterwards.
+        /// Re-fetch the view (and re-export the buffer) after every
+        /// growth rather than holding one across a call that might
+        /// trigger it.
+        ///
+        /// [buffer-protocol]: https://docs.python.org/3/c-api/buffer.html
+        ///
         /// ## Example
         ///
         /// This is an example for the `Uint8Array` view, but it is
@@ -56,6 +196,161 @@ macro_rules! memory_view {
             fn bytes_per_element(&self) -> u8 {
                 $bytes_per_element
             }
+
+            /// Reads `length` elements starting at `offset` and
+            /// returns them as a contiguous `bytes` copy, without
+            /// going through `__getitem__` one element at a time.
+            ///
+            /// Raises `IndexError` if `offset..offset + length` runs
+            /// past the end of the memory, rather than silently
+            /// truncating the result.
+            #[args(offset = 0, length = "None")]
+            fn read(&self, offset: usize, length: Option<usize>) -> PyResult<Py<PyBytes>> {
+                let view = self.memory.view::<$wasm_type>();
+                let view = &view[self.offset..];
+                let length = length.unwrap_or_else(|| view.len().saturating_sub(offset));
+                let range = checked_range(offset, length, view.len()).map_err(|error| match error {
+                    RangeError::Overflow => {
+                        to_py_err::<PyIndexError, _>("Out of bound: `offset + length` overflows")
+                    }
+                    RangeError::OutOfBounds => to_py_err::<PyIndexError, _>(format!(
+                        "Out of bound: Cannot read {} element(s) at offset {}; memory size is {}",
+                        length,
+                        offset,
+                        view.len()
+                    )),
+                })?;
+
+                let bytes: Vec<u8> = view[range]
+                    .iter()
+                    .map(Cell::get)
+                    .flat_map(|element| element.to_ne_bytes().to_vec())
+                    .collect();
+
+                let gil = Python::acquire_gil();
+                let py = gil.python();
+
+                Ok(PyBytes::new(py, &bytes).into())
+            }
+
+            /// Writes the content of a Python buffer (`bytes`,
+            /// `bytearray`, `memoryview`, etc.) into the view,
+            /// starting at `offset`.
+            ///
+            /// Raises `IndexError` if the data would not fit in the
+            /// remaining memory, rather than silently truncating it.
+            #[args(offset = 0)]
+            fn write(&self, data: &PyAny, offset: usize) -> PyResult<()> {
+                let buffer = pyo3::buffer::PyBuffer::<u8>::get(data)?;
+                let bytes = buffer.to_vec(data.py())?;
+
+                if !is_whole_number_of_elements(bytes.len(), $bytes_per_element as usize) {
+                    return Err(to_py_err::<PyValueError, _>(format!(
+                        "The given buffer's length ({}) is not a multiple of the element size ({})",
+                        bytes.len(),
+                        $bytes_per_element
+                    )));
+                }
+
+                let view = self.memory.view::<$wasm_type>();
+                let view = &view[self.offset..];
+                let length = bytes.len() / $bytes_per_element as usize;
+                let range = checked_range(offset, length, view.len()).map_err(|error| match error {
+                    RangeError::Overflow => {
+                        to_py_err::<PyIndexError, _>("Out of bound: `offset + length` overflows")
+                    }
+                    RangeError::OutOfBounds => to_py_err::<PyIndexError, _>(format!(
+                        "Out of bound: Cannot write {} element(s) at offset {}; memory size is {}",
+                        length,
+                        offset,
+                        view.len()
+                    )),
+                })?;
+
+                for (index, chunk) in bytes.chunks_exact($bytes_per_element as usize).enumerate() {
+                    let mut buf = [0u8; size_of::<$wasm_type>()];
+                    buf.copy_from_slice(chunk);
+
+                    view[range.start + index].set(<$wasm_type>::from_ne_bytes(buf));
+                }
+
+                Ok(())
+            }
+        }
+
+        #[pyproto]
+        impl PyBufferProtocol for $class_name {
+            /// Fills in a `Py_buffer` that points directly at the
+            /// guest memory backing this view, so that
+            /// `numpy.frombuffer`, `bytes()`, `memoryview()` and
+            /// `struct.unpack` can read it without copying.
+            ///
+            /// The returned pointer is only valid until the backing
+            /// `Memory` grows (see the "Buffer protocol caveat" note
+            /// above); unlike `__getitem__`/`__setitem__`, which
+            /// re-fetch `self.memory.view()` fresh on every call,
+            /// this pointer outlives the call and is not re-validated.
+            fn bf_getbuffer(&mut self, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+                if view.is_null() {
+                    return Err(to_py_err::<PyRuntimeError, _>("Cannot create a buffer from a null view"));
+                }
+
+                let memory_view = self.memory.view::<$wasm_type>();
+                let memory_view = &memory_view[self.offset..];
+                let data = memory_view.as_ptr() as *mut std::os::raw::c_void;
+                let length = memory_view.len();
+
+                unsafe {
+                    (*view).obj = ptr::null_mut();
+                    (*view).buf = data;
+                    (*view).len = (length * $bytes_per_element as usize) as isize;
+                    (*view).readonly = 0;
+                    (*view).itemsize = $bytes_per_element as isize;
+
+                    (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+                        let format = std::ffi::CString::new($format).unwrap();
+
+                        format.into_raw()
+                    } else {
+                        ptr::null_mut()
+                    };
+
+                    (*view).ndim = 1;
+                    (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+                        Box::into_raw(Box::new(length as isize))
+                    } else {
+                        ptr::null_mut()
+                    };
+                    (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+                        Box::into_raw(Box::new($bytes_per_element as isize))
+                    } else {
+                        ptr::null_mut()
+                    };
+                    (*view).suboffsets = ptr::null_mut();
+                    (*view).internal = ptr::null_mut();
+
+                    ffi::Py_INCREF(self.as_ptr());
+                    (*view).obj = self.as_ptr();
+                }
+
+                Ok(())
+            }
+
+            fn bf_releasebuffer(&mut self, view: *mut ffi::Py_buffer) {
+                unsafe {
+                    if !(*view).format.is_null() {
+                        let _ = std::ffi::CString::from_raw((*view).format);
+                    }
+
+                    if !(*view).shape.is_null() {
+                        let _ = Box::from_raw((*view).shape);
+                    }
+
+                    if !(*view).strides.is_null() {
+                        let _ = Box::from_raw((*view).strides);
+                    }
+                }
+            }
         }
 
         #[pyproto]
@@ -67,69 +362,49 @@ macro_rules! memory_view {
 
             /// Returns one or more values from the memory view.
             ///
-            /// The `index` can be either a slice or an integer.
+            /// The `index` can be either a slice or an integer, and
+            /// follows the same semantics as a regular Python
+            /// sequence: negative indices count from the end, and
+            /// slices support positive or negative steps, exactly as
+            /// `PySlice::indices` resolves them for `__setitem__`.
             fn __getitem__(&self, index: &PyAny) -> PyResult<PyObject> {
                 let view = self.memory.view::<$wasm_type>();
                 let offset = self.offset;
-                let range = if let Ok(slice) = index.cast_as::<PySlice>() {
-                    let slice = slice.indices(view.len() as _)?;
+                // The length as seen from Python, i.e. the same
+                // quantity `__len__` exposes: the view starts at
+                // `offset`, not at the beginning of linear memory.
+                let len = view.len() - offset;
 
-                    if slice.start >= slice.stop {
-                        return Err(to_py_err::<PyIndexError, _>(format!(
-                            "Slice `{}:{}` cannot be empty",
-                            slice.start, slice.stop
-                        )));
-                    } else if slice.step > 1 {
-                        return Err(to_py_err::<PyIndexError, _>(format!(
-                            "Slice must have a step of 1 for now; given {}",
-                            slice.step
-                        )));
-                    }
+                let gil = Python::acquire_gil();
+                let py = gil.python();
 
-                    (offset + slice.start as usize)..(min(offset + slice.stop as usize, view.len()))
-                } else if let Ok(index) = index.extract::<isize>() {
-                    if index < 0 {
-                        return Err(to_py_err::<PyIndexError, _>(
-                            "Out of bound: Index cannot be negative",
-                        ));
-                    }
+                if let Ok(slice) = index.cast_as::<PySlice>() {
+                    let slice = slice.indices(len as _)?;
+                    let indices = slice_elements(slice.start, slice.step, slice.slicelength);
 
-                    let index = offset + index as usize;
+                    return Ok(indices
+                        .into_iter()
+                        .map(|index| view[offset + index].get())
+                        .collect::<Vec<$wasm_type>>()
+                        .into_py(py));
+                }
 
-                    #[allow(clippy::range_plus_one)]
-                    // Writing `index..=index` makes Clippy happy but
-                    // the type of this expression is
-                    // `RangeInclusive`, when the type of `range` is
-                    // `Range`.
-                    {
-                        index..index + 1
-                    }
+                let index = if let Ok(index) = index.extract::<isize>() {
+                    index
                 } else {
                     return Err(to_py_err::<PyValueError, _>(
                         "Only integers and slices are valid to represent an index",
                     ));
                 };
 
-                if view.len() <= (range.end - 1) {
-                    return Err(to_py_err::<PyIndexError, _>(format!(
-                        "Out of bound: Maximum index {} is larger than the memory size {}",
-                        range.end - 1,
-                        view.len()
-                    )));
-                }
+                let resolved_index = resolve_index(index, len).ok_or_else(|| {
+                    to_py_err::<PyIndexError, _>(format!(
+                        "Out of bound: Index {} is out of range for a memory view of size {}",
+                        index, len
+                    ))
+                })?;
 
-                let gil = Python::acquire_gil();
-                let py = gil.python();
-
-                if range.end - range.start == 1 {
-                    Ok(view[range.start].get().into_py(py))
-                } else {
-                    Ok(view[range]
-                        .iter()
-                        .map(Cell::get)
-                        .collect::<Vec<$wasm_type>>()
-                        .into_py(py))
-                }
+                Ok(view[offset + resolved_index].get().into_py(py))
             }
 
             /// Sets one or more values in the memory view.
@@ -223,13 +498,13 @@ macro_rules! memory_view {
     };
 }
 
-memory_view!(Uint8Array over u8|1);
-memory_view!(Int8Array over i8|1);
-memory_view!(Uint16Array over u16|2);
-memory_view!(Int16Array over i16|2);
-memory_view!(Uint32Array over u32|4);
-memory_view!(Int32Array over i32|4);
-memory_view!(Uint64Array over u64|8);
-memory_view!(Int64Array over i64|8);
-memory_view!(Float32Array over f32|4);
-memory_view!(Float64Array over f64|8);
+memory_view!(Uint8Array over u8|1|"B");
+memory_view!(Int8Array over i8|1|"b");
+memory_view!(Uint16Array over u16|2|"H");
+memory_view!(Int16Array over i16|2|"h");
+memory_view!(Uint32Array over u32|4|"I");
+memory_view!(Int32Array over i32|4|"i");
+memory_view!(Uint64Array over u64|8|"Q");
+memory_view!(Int64Array over i64|8|"q");
+memory_view!(Float32Array over f32|4|"f");
+memory_view!(Float64Array over f64|8|"d");
@@ -0,0 +1,70 @@
+use crate::wasmer_inner::wasmer;
+use pyo3::prelude::*;
+use std::sync::Arc;
+use wasmer_middlewares::metering;
+
+/// Configures gas metering for a `Store`: a per-operator cost
+/// function together with the initial number of points the guest is
+/// allowed to spend before execution traps.
+///
+/// `cost` is called once per WebAssembly operator while a `Module`
+/// compiles against a metered store, and must return how many
+/// points that operator costs.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store, Metering
+///
+/// store = Store(metering=Metering(limit=1_000_000, cost=lambda operator: 1))
+/// ```
+#[pyclass]
+#[derive(Clone)]
+pub struct Metering {
+    pub(crate) limit: u64,
+    pub(crate) cost: Py<PyAny>,
+}
+
+#[pymethods]
+impl Metering {
+    #[new]
+    fn new(limit: u64, cost: Py<PyAny>) -> Self {
+        Self { limit, cost }
+    }
+}
+
+impl Metering {
+    /// Turns this configuration into a `wasmer` compiler middleware
+    /// that decrements a points counter for every executed opcode,
+    /// calling back into `self.cost` to price each operator.
+    pub(crate) fn middleware(
+        &self,
+    ) -> Arc<metering::Metering<impl Fn(&wasmer::wasmparser::Operator) -> u64 + Send + Sync>> {
+        let cost = self.cost.clone();
+
+        Arc::new(metering::Metering::new(self.limit, move |operator| {
+            Python::with_gil(|py| {
+                match cost
+                    .call1(py, (format!("{:?}", operator),))
+                    .and_then(|points| points.extract::<u64>(py))
+                {
+                    Ok(points) => points,
+                    Err(error) => {
+                        // The cost function's signature doesn't let
+                        // us propagate a `PyErr` to the caller, so
+                        // print it like an unraisable exception and
+                        // fall back to a cost of 1 rather than
+                        // silently mis-pricing the operator.
+                        error.print(py);
+                        eprintln!(
+                            "wasmer: the `Metering` cost function raised or returned a \
+                             non-integer value; defaulting to a cost of 1"
+                        );
+
+                        1
+                    }
+                }
+            })
+        }))
+    }
+}
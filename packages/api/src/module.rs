@@ -0,0 +1,228 @@
+use crate::{
+    errors::to_py_err,
+    store::Store,
+    types::{ExportType, ImportType},
+    wasmer_inner::wasmer,
+};
+use pyo3::{exceptions::PyRuntimeError, prelude::*, types::PyBytes};
+use wasmer::wasmparser::{Name, NameSectionReader, Parser, Payload};
+
+/// Represents a compiled WebAssembly module.
+///
+/// A module is a compilation artifact. It needs to be instantiated
+/// with an `Instance` before any of its code can run.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store, Module
+///
+/// module = Module(Store(), open('tests/tests.wasm', 'rb').read())
+/// ```
+#[pyclass]
+pub struct Module {
+    inner: wasmer::Module,
+    // Parsed once at construction time (see `parse_sections`), so
+    // `custom_sections`/`name` don't re-walk the binary on every
+    // access. `imports`/`exports` are read from `inner` instead.
+    custom_sections: Vec<(String, Vec<u8>)>,
+    name: Option<String>,
+}
+
+impl Module {
+    pub(crate) fn inner(&self) -> &wasmer::Module {
+        &self.inner
+    }
+
+    fn from_parts(inner: wasmer::Module, bytes: &[u8]) -> Self {
+        let (custom_sections, name) = parse_sections(bytes);
+
+        Self {
+            inner,
+            custom_sections,
+            name,
+        }
+    }
+}
+
+#[pymethods]
+impl Module {
+    /// Compiles the given bytes into a module.
+    #[new]
+    fn new(store: &Store, bytes: &PyBytes) -> PyResult<Self> {
+        let bytes = bytes.as_bytes();
+        let inner = wasmer::Module::new(store.inner(), bytes)
+            .map_err(to_py_err::<PyRuntimeError, _>)?;
+
+        Ok(Self::from_parts(inner, bytes))
+    }
+
+    /// Serializes the compiled module into bytes, so that it can be
+    /// cached and later reloaded with `Module.deserialize`.
+    fn serialize<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        self.inner
+            .serialize()
+            .map(|bytes| PyBytes::new(py, &bytes))
+            .map_err(to_py_err::<PyRuntimeError, _>)
+    }
+
+    /// Deserializes bytes produced by `Module.serialize` back into a
+    /// module, without recompiling it.
+    #[staticmethod]
+    fn deserialize(store: &Store, bytes: &PyBytes) -> PyResult<Self> {
+        let bytes = bytes.as_bytes();
+        // Safe as long as `bytes` was produced by a trusted call to
+        // `Module.serialize`, like the rest of the `wasmer` API.
+        let inner = unsafe { wasmer::Module::deserialize(store.inner(), bytes) }
+            .map_err(to_py_err::<PyRuntimeError, _>)?;
+
+        Ok(Self::from_parts(inner, bytes))
+    }
+
+    /// Lists the module's imports, in declaration order.
+    #[getter]
+    fn imports(&self) -> Vec<ImportType> {
+        self.inner.imports().map(Into::into).collect()
+    }
+
+    /// Lists the module's exports, in declaration order.
+    #[getter]
+    fn exports(&self) -> Vec<ExportType> {
+        self.inner.exports().map(Into::into).collect()
+    }
+
+    /// Returns the raw payloads of every custom section with the
+    /// given `name`, in the order they appear in the binary.
+    fn custom_sections<'py>(&self, py: Python<'py>, name: &str) -> Vec<&'py PyBytes> {
+        self.custom_sections
+            .iter()
+            .filter(|(section_name, _)| section_name == name)
+            .map(|(_, payload)| PyBytes::new(py, payload))
+            .collect()
+    }
+
+    /// The module's name, decoded from the module-name subsection of
+    /// the `name` custom section, or `None` if it isn't present.
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+}
+
+/// Walks the section headers of `wasm` once via `wasmparser`,
+/// collecting every custom section together with the module name
+/// decoded from the `name` section's module-name subsection, if
+/// present.
+///
+/// Custom sections aren't validated by the core Wasm validator, so
+/// this relies on `wasmparser`'s bounds-checked readers rather than a
+/// hand-rolled walk over untrusted varints.
+fn parse_sections(wasm: &[u8]) -> (Vec<(String, Vec<u8>)>, Option<String>) {
+    let mut custom_sections = Vec::new();
+    let mut name = None;
+
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = match payload {
+            Ok(payload) => payload,
+            // `wasmer::Module::new` already validated the module
+            // before this runs; stop rather than trust a malformed
+            // tail any further.
+            Err(_) => break,
+        };
+
+        if let Payload::CustomSection(reader) = payload {
+            let section_name = reader.name().to_string();
+
+            if section_name == "name" {
+                if let Ok(name_reader) =
+                    NameSectionReader::new(reader.data(), reader.data_offset())
+                {
+                    for entry in name_reader {
+                        if let Ok(Name::Module(module_name)) = entry {
+                            name = Some(module_name.to_string());
+                        }
+                    }
+                }
+            }
+
+            custom_sections.push((section_name, reader.data().to_vec()));
+        }
+    }
+
+    (custom_sections, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uleb128(mut value: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        loop {
+            let mut byte = (value & 0x7f) as u8;
+            value >>= 7;
+
+            if value != 0 {
+                byte |= 0x80;
+            }
+
+            bytes.push(byte);
+
+            if value == 0 {
+                break;
+            }
+        }
+
+        bytes
+    }
+
+    fn custom_section(name: &str, payload: &[u8]) -> Vec<u8> {
+        let mut content = uleb128(name.len() as u32);
+        content.extend_from_slice(name.as_bytes());
+        content.extend_from_slice(payload);
+
+        let mut section = vec![0u8]; // custom section id
+        section.extend(uleb128(content.len() as u32));
+        section.extend(content);
+        section
+    }
+
+    fn minimal_module(sections: &[Vec<u8>]) -> Vec<u8> {
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+        for section in sections {
+            wasm.extend_from_slice(section);
+        }
+
+        wasm
+    }
+
+    #[test]
+    fn parse_sections_collects_custom_sections() {
+        let wasm = minimal_module(&[custom_section("hello", b"world")]);
+        let (custom_sections, _) = parse_sections(&wasm);
+
+        assert_eq!(
+            custom_sections,
+            vec![("hello".to_string(), b"world".to_vec())]
+        );
+    }
+
+    #[test]
+    fn parse_sections_decodes_the_module_name() {
+        let module_name = b"test_module";
+
+        let mut inner = uleb128(module_name.len() as u32);
+        inner.extend_from_slice(module_name);
+
+        let mut name_payload = vec![0u8]; // module-name subsection id
+        name_payload.extend(uleb128(inner.len() as u32));
+        name_payload.extend(inner);
+
+        let wasm = minimal_module(&[custom_section("name", &name_payload)]);
+        let (_, name) = parse_sections(&wasm);
+
+        assert_eq!(name.as_deref(), Some("test_module"));
+    }
+}
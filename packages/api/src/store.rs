@@ -0,0 +1,65 @@
+use crate::{features::Features, metering::Metering, wasmer_inner::wasmer};
+use pyo3::prelude::*;
+use wasmer_compiler_cranelift::Cranelift;
+use wasmer_engine_universal::Universal;
+
+/// Represents the store, the top-level type in Wasmer.
+///
+/// A store groups together an engine and all the runtime state a
+/// `Module` is compiled against and an `Instance` runs against.
+///
+/// Passing `metering=Metering(...)` makes the store compile modules
+/// with gas accounting enabled, so guest execution can be bounded;
+/// see `Metering` and `Instance.get_remaining_points` /
+/// `Instance.set_remaining_points`. A metered store can be reused to
+/// instantiate any number of `Instance`s; each tracks its own gas
+/// independently.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store
+///
+/// store = Store()
+/// ```
+#[pyclass]
+pub struct Store {
+    inner: wasmer::Store,
+    is_metered: bool,
+}
+
+impl Store {
+    pub(crate) fn inner(&self) -> &wasmer::Store {
+        &self.inner
+    }
+
+    /// Whether this store was created with a `Metering`
+    /// configuration, i.e. whether instances compiled against it
+    /// track gas points.
+    pub(crate) fn is_metered(&self) -> bool {
+        self.is_metered
+    }
+}
+
+#[pymethods]
+impl Store {
+    #[new]
+    #[args(metering = "None", features = "None")]
+    fn new(metering: Option<Metering>, features: Option<Features>) -> Self {
+        let mut compiler_config = Cranelift::default();
+        let is_metered = metering.is_some();
+
+        if let Some(metering) = &metering {
+            compiler_config.push_middleware(metering.middleware());
+        }
+
+        let engine = Universal::new(compiler_config)
+            .features(features.unwrap_or_default().into())
+            .engine();
+
+        Self {
+            inner: wasmer::Store::new(&engine),
+            is_metered,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::wasmer_inner::wasmer;
+use pyo3::prelude::*;
+
+/// Represents the type of a module export: its name, together with
+/// the extern type it exposes (function, memory, table or global).
+///
+/// It is returned by the `Module.exports` getter.
+#[pyclass]
+#[derive(Clone)]
+pub struct ExportType {
+    pub(crate) inner: wasmer::ExportType,
+}
+
+#[pymethods]
+impl ExportType {
+    /// The name under which this item is exported.
+    #[getter]
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// A short tag identifying the extern type this export
+    /// provides: `"function"`, `"memory"`, `"table"` or `"global"`.
+    #[getter]
+    fn kind(&self) -> &'static str {
+        extern_type_kind(self.inner.ty())
+    }
+}
+
+impl From<wasmer::ExportType> for ExportType {
+    fn from(inner: wasmer::ExportType) -> Self {
+        Self { inner }
+    }
+}
+
+/// Represents the type of a module import: the module it is
+/// imported from, its name, and the extern type it expects.
+///
+/// It is returned by the `Module.imports` getter.
+#[pyclass]
+#[derive(Clone)]
+pub struct ImportType {
+    pub(crate) inner: wasmer::ImportType,
+}
+
+#[pymethods]
+impl ImportType {
+    /// The name of the module this import is expected from.
+    #[getter]
+    fn module(&self) -> &str {
+        self.inner.module()
+    }
+
+    /// The name under which this item is imported.
+    #[getter]
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// A short tag identifying the extern type this import expects:
+    /// `"function"`, `"memory"`, `"table"` or `"global"`.
+    #[getter]
+    fn kind(&self) -> &'static str {
+        extern_type_kind(self.inner.ty())
+    }
+}
+
+impl From<wasmer::ImportType> for ImportType {
+    fn from(inner: wasmer::ImportType) -> Self {
+        Self { inner }
+    }
+}
+
+fn extern_type_kind(ty: &wasmer::ExternType) -> &'static str {
+    match ty {
+        wasmer::ExternType::Function(_) => "function",
+        wasmer::ExternType::Memory(_) => "memory",
+        wasmer::ExternType::Table(_) => "table",
+        wasmer::ExternType::Global(_) => "global",
+    }
+}